@@ -1,27 +1,166 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::io::{BufRead, BufReader};
-use std::process::{Command, Stdio};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::Duration;
+use semver::Version;
+use sysinfo::{Pid, System};
 use tauri::{AppHandle, Emitter};
+use tokio::time::sleep;
+use uuid::Uuid;
+
+/// How often the supervisor checks on the child process and, once it's
+/// healthy, how often it re-confirms that health to decide when to reset
+/// the restart counter.
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long the server must stay healthy before a crash is no longer
+/// counted against `max_restarts`.
+const SUPERVISOR_STABLE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Identifies one managed (or attached-to) server inside `AppState`'s
+/// instance registry.
+pub type ServerId = String;
+
+/// Id of the instance used by callers that don't care about managing more
+/// than one server - preserves the single-server behavior this API had
+/// before `AppState` became a registry.
+pub const DEFAULT_SERVER_ID: &str = "default";
+
+/// Port used to seed a brand new instance's status before it's ever been
+/// started, matching the old single-instance default.
+const DEFAULT_INSTANCE_PORT: u16 = 3001;
+
+/// Allocate a fresh id for a new server instance, the way `start_server_impl`
+/// is asked to manage one beyond the default. Callers hang onto the
+/// returned id and pass it to subsequent `*_impl` calls to address that
+/// specific instance.
+pub fn allocate_server_id() -> ServerId {
+    Uuid::new_v4().to_string()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SupervisorConfig {
+    pub max_restarts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub enabled: bool,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            max_restarts: 5,
+            base_delay_ms: 500,
+            max_delay_ms: 30_000,
+            enabled: true,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SupervisorHandle {
+    /// Set by `stop_server_impl` so the supervisor knows an exit was
+    /// intentional and shouldn't trigger a restart.
+    stopping: Arc<AtomicBool>,
+    /// Number of consecutive restarts since the server last stayed healthy
+    /// for `SUPERVISOR_STABLE_WINDOW`.
+    attempt: Arc<AtomicU32>,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerStatus {
+    pub id: ServerId,
     pub running: bool,
     pub port: u16,
     pub pid: Option<u32>,
     pub health_url: String,
+    /// Host the server is reachable at, carried alongside `health_url` so
+    /// callers (notably `stop_server_impl`'s force-external path) can tell
+    /// whether it's safe to act on `port` as a *local* process.
+    pub host: String,
     pub external: bool,
+    pub capabilities: Option<ServerCapabilities>,
+    pub process_info: Option<ProcessInfo>,
+    /// How many times the supervisor has respawned the current server
+    /// since it was last started or attached to. Reset to 0 on a fresh
+    /// `start_server_impl` call; sticky across crashes so the UI can show
+    /// it even after restarts are exhausted.
+    pub restart_count: u32,
+    /// Exit code from the most recent unexpected exit, if any. Stays set
+    /// after restarts are exhausted so the UI can explain why the server
+    /// stopped.
+    pub last_exit_code: Option<i32>,
 }
 
+impl ServerStatus {
+    /// Sentinel returned for a `ServerId` that isn't (or isn't yet) in the
+    /// registry, so `get_server_status_impl` can keep returning a concrete
+    /// `ServerStatus` instead of an `Option` for callers that only know
+    /// about the default instance.
+    fn not_running(id: ServerId) -> Self {
+        Self {
+            id,
+            running: false,
+            port: 0,
+            pid: None,
+            health_url: String::new(),
+            host: String::new(),
+            external: false,
+            capabilities: None,
+            process_info: None,
+            restart_count: 0,
+            last_exit_code: None,
+        }
+    }
+}
+
+/// Name and command line of the process behind `ServerStatus.pid`, so the
+/// UI can confirm it's actually the aicli server before anyone kills it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub cmd: Vec<String>,
+}
+
+/// Feature flags and protocol version a running server advertises,
+/// negotiated by [`negotiate_capabilities_impl`] before the host app
+/// trusts a server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    pub protocol_version: String,
+    pub features: Vec<String>,
+}
+
+/// Capabilities the host app requires before it will attach to a server.
+pub const REQUIRED_CAPABILITIES: &[&str] = &["health", "chat"];
+
+/// Oldest server protocol version the host app knows how to drive.
+pub const MIN_SERVER_VERSION: &str = "1.0.0";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
     pub timestamp: String,
     pub level: String,
     pub message: String,
+    /// Where the entry came from: `"stdout"`/`"stderr"` for the managed
+    /// child process, `"host"` for messages the app itself generated.
+    pub stream: String,
+    /// Subsystem that emitted the line, when the server's own structured
+    /// (NDJSON) logging reports one.
+    #[serde(default)]
+    pub component: Option<String>,
+    /// Any other keys from a structured log line that aren't captured by
+    /// the named fields above.
+    #[serde(default)]
+    pub fields: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,31 +175,156 @@ pub struct NetworkInfo {
     pub port: u16,
 }
 
-pub struct AppState {
-    pub server_process: Mutex<Option<std::process::Child>>,
-    pub server_status: Mutex<ServerStatus>,
-    pub logs: Arc<Mutex<LogsState>>,
+/// URI scheme for reaching a [`ServerTarget`]. `Http` covers the common
+/// case of a companion server on the LAN; `Https` is there for a server
+/// sitting behind a TLS-terminating proxy or tunnel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    Http,
+    Https,
 }
 
-impl AppState {
-    pub fn new() -> Self {
+impl Scheme {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+        }
+    }
+}
+
+/// Where to reach a server - not necessarily this machine. Replaces the
+/// `http://localhost:{port}` hardcoded throughout before remote/LAN
+/// attachment was supported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerTarget {
+    pub host: String,
+    pub port: u16,
+    pub scheme: Scheme,
+}
+
+impl ServerTarget {
+    pub fn new(host: impl Into<String>, port: u16, scheme: Scheme) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            scheme,
+        }
+    }
+
+    /// Target for a server this app manages directly on the local machine -
+    /// the only kind of target that existed before remote attachment.
+    pub fn loopback(port: u16) -> Self {
+        Self::new("localhost", port, Scheme::Http)
+    }
+
+    /// Whether this target is reachable only from this machine. A
+    /// non-loopback target is always `external` (this app can't have
+    /// spawned a process on another host) and can't be found or killed via
+    /// `find_process_by_port`, which only inspects the local machine.
+    pub fn is_loopback(&self) -> bool {
+        Self::is_loopback_host(&self.host)
+    }
+
+    /// Same check as `is_loopback`, for callers that only have a host
+    /// string on hand (e.g. `ServerStatus.host`) rather than a full target.
+    pub fn is_loopback_host(host: &str) -> bool {
+        matches!(host, "localhost" | "127.0.0.1" | "::1")
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("{}://{}:{}", self.scheme.as_str(), self.host, self.port)
+    }
+
+    pub fn health_url(&self) -> String {
+        format!("{}/health", self.base_url())
+    }
+}
+
+/// Everything needed to run and supervise one server: its child handle,
+/// status, logs, and supervisor config/handle. `AppState` keys a registry
+/// of these by `ServerId` so the host app can run more than one server
+/// (different ports, dev vs prod, per-project configs) at once.
+struct ServerInstance {
+    server_process: Mutex<Option<std::process::Child>>,
+    server_status: Mutex<ServerStatus>,
+    logs: Arc<Mutex<LogsState>>,
+    supervisor_config: Mutex<SupervisorConfig>,
+    supervisor: Mutex<Option<SupervisorHandle>>,
+    /// Set while `start_server_impl` is between claiming this instance (no
+    /// `server_process` yet) and installing the `Child` it spawns - the
+    /// spawn and readiness wait are async, so without this a second
+    /// concurrent `start_server_impl` call could pass the "not already
+    /// managed" check too and spawn a duplicate, orphaned process.
+    starting: AtomicBool,
+}
+
+impl ServerInstance {
+    fn new(id: ServerId, port: u16) -> Self {
         Self {
             server_process: Mutex::new(None),
             server_status: Mutex::new(ServerStatus {
+                id,
                 running: false,
-                port: 3001,
+                port,
                 pid: None,
-                health_url: "http://localhost:3001/health".to_string(),
+                health_url: ServerTarget::loopback(port).health_url(),
+                host: ServerTarget::loopback(port).host,
                 external: false,
+                capabilities: None,
+                process_info: None,
+                restart_count: 0,
+                last_exit_code: None,
             }),
             logs: Arc::new(Mutex::new(LogsState {
                 entries: Vec::new(),
                 max_entries: 5000, // Reduced from 10000 for better performance
             })),
+            supervisor_config: Mutex::new(SupervisorConfig::default()),
+            supervisor: Mutex::new(None),
+            starting: AtomicBool::new(false),
         }
     }
 }
 
+pub struct AppState {
+    instances: Mutex<HashMap<ServerId, Arc<ServerInstance>>>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        let mut instances = HashMap::new();
+        instances.insert(
+            DEFAULT_SERVER_ID.to_string(),
+            Arc::new(ServerInstance::new(
+                DEFAULT_SERVER_ID.to_string(),
+                DEFAULT_INSTANCE_PORT,
+            )),
+        );
+        Self {
+            instances: Mutex::new(instances),
+        }
+    }
+
+    /// Fetch the named instance, creating it (seeded for `port`) if it
+    /// doesn't exist yet. `Arc`-cloned out so callers don't hold the
+    /// registry's `MutexGuard` across an `.await`.
+    fn instance(&self, id: &str, port: u16) -> Arc<ServerInstance> {
+        let mut instances = self.instances.lock().unwrap();
+        Arc::clone(
+            instances
+                .entry(id.to_string())
+                .or_insert_with(|| Arc::new(ServerInstance::new(id.to_string(), port))),
+        )
+    }
+
+    /// Fetch the named instance without creating it.
+    fn find_instance(&self, id: &str) -> Option<Arc<ServerInstance>> {
+        self.instances.lock().unwrap().get(id).cloned()
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self::new()
@@ -74,18 +338,115 @@ pub fn get_local_ip() -> Result<String, String> {
     }
 }
 
+/// LAN address and port another device can use to reach a server this host
+/// is managing, so the UI can display/share a connectable URL.
+pub fn shareable_network_info_impl(port: u16) -> Result<NetworkInfo, String> {
+    let ip = get_local_ip()?;
+    Ok(NetworkInfo { ip, port })
+}
+
 // Helper function to add log entry
 fn add_log_entry(logs: &Arc<Mutex<LogsState>>, level: &str, message: String, app_handle: Option<&AppHandle>) {
+    add_log_entry_from(logs, level, message, "host", app_handle)
+}
+
+fn add_log_entry_from(
+    logs: &Arc<Mutex<LogsState>>,
+    level: &str,
+    message: String,
+    stream: &str,
+    app_handle: Option<&AppHandle>,
+) {
     let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string();
-    let entry = LogEntry {
-        timestamp,
-        level: level.to_string(),
+    push_log_entry(
+        logs,
+        LogEntry {
+            timestamp,
+            level: level.to_string(),
+            message,
+            stream: stream.to_string(),
+            component: None,
+            fields: HashMap::new(),
+        },
+        app_handle,
+    );
+}
+
+/// A captured line that parsed as a structured (NDJSON) log record, with
+/// the well-known fields pulled out and everything else kept around for
+/// `LogEntry.fields`.
+struct StructuredLogLine {
+    level: String,
+    message: String,
+    timestamp: Option<String>,
+    component: Option<String>,
+    fields: HashMap<String, serde_json::Value>,
+}
+
+/// Try to parse a captured stdout/stderr line as one JSON object. Returns
+/// `None` for anything that isn't a JSON object, so the caller can fall
+/// back to the older substring-based level heuristic for plain text.
+fn parse_structured_log_line(line: &str) -> Option<StructuredLogLine> {
+    let value: serde_json::Value = serde_json::from_str(line.trim()).ok()?;
+    let mut fields = value.as_object()?.clone();
+
+    let level = fields
+        .remove("level")
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| "info".to_string());
+    let message = fields
+        .remove("message")
+        .or_else(|| fields.remove("msg"))
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| line.to_string());
+    let timestamp = fields
+        .remove("timestamp")
+        .or_else(|| fields.remove("time"))
+        .and_then(|v| v.as_str().map(str::to_string));
+    let component = fields
+        .remove("component")
+        .or_else(|| fields.remove("module"))
+        .and_then(|v| v.as_str().map(str::to_string));
+
+    Some(StructuredLogLine {
+        level,
         message,
-    };
+        timestamp,
+        component,
+        fields: fields.into_iter().collect(),
+    })
+}
 
+/// Record a line that `parse_structured_log_line` successfully parsed,
+/// keeping its own level/timestamp/component/fields instead of the
+/// heuristic `add_log_entry_from` falls back to for plain text.
+fn add_structured_log_entry(
+    logs: &Arc<Mutex<LogsState>>,
+    parsed: StructuredLogLine,
+    stream: &str,
+    app_handle: Option<&AppHandle>,
+) {
+    let timestamp = parsed
+        .timestamp
+        .unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string());
+    push_log_entry(
+        logs,
+        LogEntry {
+            timestamp,
+            level: parsed.level,
+            message: parsed.message,
+            stream: stream.to_string(),
+            component: parsed.component,
+            fields: parsed.fields,
+        },
+        app_handle,
+    );
+}
+
+fn push_log_entry(logs: &Arc<Mutex<LogsState>>, entry: LogEntry, app_handle: Option<&AppHandle>) {
     let mut logs_guard = logs.lock().unwrap();
     logs_guard.entries.push(entry.clone());
-    
+
     // Keep only the last max_entries
     let len = logs_guard.entries.len();
     let max = logs_guard.max_entries;
@@ -93,41 +454,138 @@ fn add_log_entry(logs: &Arc<Mutex<LogsState>>, level: &str, message: String, app
         logs_guard.entries.drain(0..len - max);
     }
     drop(logs_guard);
-    
+
     // Emit log event if app handle is provided
     if let Some(handle) = app_handle {
         let _ = handle.emit("log-entry", entry);
     }
 }
 
-// Get all logs
-pub fn get_logs_impl(state: &AppState) -> Vec<LogEntry> {
-    state.logs.lock().unwrap().entries.clone()
+// Get all logs for one instance (the default instance if `server_id` is None)
+pub fn get_logs_impl(state: &AppState, server_id: Option<&str>) -> Vec<LogEntry> {
+    let id = server_id.unwrap_or(DEFAULT_SERVER_ID);
+    match state.find_instance(id) {
+        Some(instance) => instance.logs.lock().unwrap().entries.clone(),
+        None => Vec::new(),
+    }
 }
 
-// Clear logs
-pub fn clear_logs_impl(state: &AppState) {
-    state.logs.lock().unwrap().entries.clear();
+// Clear logs for one instance (the default instance if `server_id` is None)
+pub fn clear_logs_impl(state: &AppState, server_id: Option<&str>) {
+    let id = server_id.unwrap_or(DEFAULT_SERVER_ID);
+    if let Some(instance) = state.find_instance(id) {
+        instance.logs.lock().unwrap().entries.clear();
+    }
 }
 
-// Helper function to find process ID by port
-pub fn find_process_by_port(port: u16) -> Option<u32> {
-    #[cfg(target_os = "macos")]
-    {
-        let output = Command::new("lsof")
-            .args(["-ti", &format!(":{port}")])
-            .output()
-            .ok()?;
+/// Severities in ascending order of importance, used by
+/// `get_logs_filtered_impl`'s `min_level` filter. Unrecognized levels rank
+/// as `"info"` so a stray custom level doesn't get silently dropped.
+const LOG_LEVELS: [&str; 4] = ["debug", "info", "warning", "error"];
+
+/// Common aliases from NDJSON loggers (pino, bunyan, ...) that don't match
+/// `LOG_LEVELS` verbatim, mapped onto the closest level we rank.
+fn normalize_log_level(level: &str) -> &str {
+    match level.to_ascii_lowercase().as_str() {
+        "warn" => "warning",
+        "fatal" | "crit" | "critical" => "error",
+        "trace" => "debug",
+        _ => level,
+    }
+}
 
-        if output.status.success() {
-            let pid_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            pid_str.parse::<u32>().ok()
-        } else {
-            None
+fn log_level_rank(level: &str) -> usize {
+    let normalized = normalize_log_level(level);
+    LOG_LEVELS
+        .iter()
+        .position(|l| l.eq_ignore_ascii_case(normalized))
+        .unwrap_or(1)
+}
+
+/// Query the ring buffer server-side instead of cloning all `max_entries`
+/// entries to the frontend just to filter them there. `limit` keeps the
+/// most recent matches.
+pub fn get_logs_filtered_impl(
+    state: &AppState,
+    server_id: Option<&str>,
+    min_level: Option<String>,
+    component: Option<String>,
+    substring: Option<String>,
+    limit: Option<usize>,
+) -> Vec<LogEntry> {
+    let id = server_id.unwrap_or(DEFAULT_SERVER_ID);
+    let Some(instance) = state.find_instance(id) else {
+        return Vec::new();
+    };
+
+    let min_rank = min_level.as_deref().map(log_level_rank);
+    let logs_guard = instance.logs.lock().unwrap();
+    let mut matches: Vec<LogEntry> = logs_guard
+        .entries
+        .iter()
+        .filter(|entry| min_rank.map_or(true, |min| log_level_rank(&entry.level) >= min))
+        .filter(|entry| component.as_deref().map_or(true, |c| entry.component.as_deref() == Some(c)))
+        .filter(|entry| substring.as_deref().map_or(true, |s| entry.message.contains(s)))
+        .cloned()
+        .collect();
+    drop(logs_guard);
+
+    if let Some(limit) = limit {
+        let len = matches.len();
+        if len > limit {
+            matches.drain(0..len - limit);
         }
     }
+    matches
+}
 
-    #[cfg(target_os = "linux")]
+/// File format `export_logs_impl` writes the buffer as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogExportFormat {
+    Ndjson,
+    Plaintext,
+}
+
+/// Write one instance's full log buffer to `path`, for attaching to a bug
+/// report or grepping outside the logs panel.
+pub fn export_logs_impl(
+    state: &AppState,
+    server_id: Option<&str>,
+    path: &str,
+    format: LogExportFormat,
+) -> Result<(), String> {
+    let id = server_id.unwrap_or(DEFAULT_SERVER_ID);
+    let Some(instance) = state.find_instance(id) else {
+        return Err(format!("No server instance with id '{id}'"));
+    };
+    let entries = instance.logs.lock().unwrap().entries.clone();
+
+    let contents = match format {
+        LogExportFormat::Ndjson => entries
+            .iter()
+            .map(|entry| {
+                serde_json::to_string(entry).map_err(|e| format!("Failed to serialize log entry: {e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n"),
+        LogExportFormat::Plaintext => entries
+            .iter()
+            .map(|entry| format!("[{}] [{}] [{}] {}", entry.timestamp, entry.level, entry.stream, entry.message))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write log export to '{path}': {e}"))
+}
+
+// Helper function to find process ID by port. `sysinfo` has no portable
+// port -> PID mapping, so this still has to shell out per-platform.
+// Inspecting and force-killing the process go through `sysinfo` instead;
+// the only other shell-out is the graceful `taskkill` (without `/F`) that
+// `send_terminate_signal` uses on Windows.
+pub fn find_process_by_port(port: u16) -> Option<u32> {
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     {
         let output = Command::new("lsof")
             .args(["-ti", &format!(":{port}")])
@@ -165,38 +623,49 @@ pub fn find_process_by_port(port: u16) -> Option<u32> {
     }
 }
 
-pub async fn start_server_impl(
-    state: &AppState, 
-    port: u16,
-    auth_token: Option<String>,
-    config_path: Option<String>,
-    app_handle: Option<&AppHandle>
-) -> Result<ServerStatus, String> {
-    // First check if server is already running on this port
-    let health_check = check_server_health_impl(port).await?;
-
-    if health_check {
-        // Server is already running externally
-        let mut status_guard = state.server_status.lock().unwrap();
-        *status_guard = ServerStatus {
-            running: true,
-            port,
-            pid: None,
-            health_url: format!("http://localhost:{port}/health"),
-            external: true,
-        };
-        return Ok(status_guard.clone());
-    }
-
-    let mut process_guard = state.server_process.lock().unwrap();
+/// Look up a process's name and command line via `sysinfo` so the UI (and
+/// `stop_server_impl`'s force-kill path) can confirm it's actually the
+/// aicli server before anyone acts on it.
+pub fn describe_process(pid: u32) -> Option<ProcessInfo> {
+    let mut system = System::new();
+    let sys_pid = Pid::from_u32(pid);
+    system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[sys_pid]), true);
+    let process = system.process(sys_pid)?;
+    Some(ProcessInfo {
+        pid,
+        name: process.name().to_string_lossy().into_owned(),
+        cmd: process
+            .cmd()
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect(),
+    })
+}
 
-    // Check if we already have a process
-    if process_guard.is_some() {
-        return Err("Server process is already managed".to_string());
+/// Forcibly terminate a process by PID via `sysinfo`, replacing the old
+/// `kill -9`/`taskkill /F` shell-outs so the forced kill itself needs no CLI
+/// tools. Finding an external server's PID still goes through
+/// `find_process_by_port`, which shells out to `lsof`/`netstat`.
+pub fn kill_process(pid: u32) -> Result<(), String> {
+    let mut system = System::new();
+    let sys_pid = Pid::from_u32(pid);
+    system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[sys_pid]), true);
+
+    match system.process(sys_pid) {
+        Some(process) => {
+            if process.kill() {
+                Ok(())
+            } else {
+                Err(format!("Failed to terminate process {pid}"))
+            }
+        }
+        None => Err(format!("No such process: {pid}")),
     }
+}
 
-    // Get server directory - different approach for dev vs prod
-    let server_dir = if cfg!(debug_assertions) {
+// Get server directory - different approach for dev vs prod
+fn resolve_server_dir() -> Result<PathBuf, String> {
+    if cfg!(debug_assertions) {
         // Development: Find the server directory relative to the desktop project
         let current_dir =
             env::current_dir().map_err(|e| format!("Failed to get current directory: {e}"))?;
@@ -206,14 +675,14 @@ pub async fn start_server_impl(
         loop {
             let potential_server = search_dir.join("server");
             if potential_server.join("src").join("index.js").exists() {
-                break potential_server;
+                return Ok(potential_server);
             }
 
             let parent_server = search_dir.join("../server");
             if parent_server.join("src").join("index.js").exists() {
-                break parent_server
+                return parent_server
                     .canonicalize()
-                    .map_err(|e| format!("Failed to canonicalize path: {e}"))?;
+                    .map_err(|e| format!("Failed to canonicalize path: {e}"));
             }
 
             match search_dir.parent() {
@@ -228,39 +697,63 @@ pub async fn start_server_impl(
 
         let exe_dir = current_exe.parent().ok_or("Failed to get exe directory")?;
 
-        exe_dir.join("server")
-    };
+        Ok(exe_dir.join("server"))
+    }
+}
 
-    // Start the server
+fn build_server_command(
+    server_dir: &PathBuf,
+    port: u16,
+    auth_token: &Option<String>,
+    config_path: &Option<String>,
+) -> Command {
     let mut cmd = Command::new("node");
     cmd.arg("src/index.js")
         .current_dir(server_dir)
         .env("PORT", port.to_string())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
-    
-    // Add auth token if provided
+
     if let Some(token) = auth_token {
         cmd.env("AUTH_TOKEN", token);
     }
-    
-    // Add config path if provided
+
     if let Some(path) = config_path {
         cmd.env("CONFIG_PATH", path);
     }
 
-    match cmd.spawn() {
-        Ok(mut child) => {
-            let pid = child.id();
-            
-            // Set up log capturing for stdout
-            if let Some(stdout) = child.stdout.take() {
-                let logs_clone = Arc::clone(&state.logs);
-                let app_handle_clone = app_handle.cloned();
-                thread::spawn(move || {
-                    let reader = BufReader::new(stdout);
-                    for line in reader.lines() {
-                        if let Ok(line) = line {
+    cmd
+}
+
+/// Spawn the Node server and wire its stdout/stderr into the shared log
+/// buffer. Does not touch the instance's `server_process` or `server_status` -
+/// callers (both the initial start and the supervisor's restarts) decide
+/// how to install the resulting `Child`.
+fn spawn_server_child(
+    server_dir: &PathBuf,
+    port: u16,
+    auth_token: &Option<String>,
+    config_path: &Option<String>,
+    logs: &Arc<Mutex<LogsState>>,
+    app_handle: Option<&AppHandle>,
+) -> Result<Child, String> {
+    let mut cmd = build_server_command(server_dir, port, auth_token, config_path);
+
+    let mut child = cmd.spawn().map_err(|e| format!("Failed to start server: {e}"))?;
+
+    // Set up log capturing for stdout. The reader loop naturally runs
+    // until the pipe closes (i.e. the child exits), so everything the
+    // process wrote is flushed into the buffer with no special-casing.
+    if let Some(stdout) = child.stdout.take() {
+        let logs_clone = Arc::clone(logs);
+        let app_handle_clone = app_handle.cloned();
+        thread::spawn(move || {
+            let reader = BufReader::new(stdout);
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    match parse_structured_log_line(&line) {
+                        Some(parsed) => add_structured_log_entry(&logs_clone, parsed, "stdout", app_handle_clone.as_ref()),
+                        None => {
                             // Determine log level based on content
                             let level = if line.contains("ERROR") || line.contains("error") {
                                 "error"
@@ -269,59 +762,467 @@ pub async fn start_server_impl(
                             } else {
                                 "info"
                             };
-                            add_log_entry(&logs_clone, level, line, app_handle_clone.as_ref());
+                            add_log_entry_from(&logs_clone, level, line, "stdout", app_handle_clone.as_ref());
                         }
                     }
-                });
+                }
             }
-            
-            // Set up log capturing for stderr
-            if let Some(stderr) = child.stderr.take() {
-                let logs_clone = Arc::clone(&state.logs);
-                let app_handle_clone = app_handle.cloned();
-                thread::spawn(move || {
-                    let reader = BufReader::new(stderr);
-                    for line in reader.lines() {
-                        if let Ok(line) = line {
-                            add_log_entry(&logs_clone, "error", line, app_handle_clone.as_ref());
-                        }
+        });
+    }
+
+    // Set up log capturing for stderr
+    if let Some(stderr) = child.stderr.take() {
+        let logs_clone = Arc::clone(logs);
+        let app_handle_clone = app_handle.cloned();
+        thread::spawn(move || {
+            let reader = BufReader::new(stderr);
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    match parse_structured_log_line(&line) {
+                        Some(parsed) => add_structured_log_entry(&logs_clone, parsed, "stderr", app_handle_clone.as_ref()),
+                        None => add_log_entry_from(&logs_clone, "error", line, "stderr", app_handle_clone.as_ref()),
                     }
-                });
+                }
+            }
+        });
+    }
+
+    Ok(child)
+}
+
+pub async fn start_server_impl(
+    state: &AppState,
+    server_id: Option<ServerId>,
+    port: u16,
+    auth_token: Option<String>,
+    config_path: Option<String>,
+    force_capabilities: Option<bool>,
+    app_handle: Option<&AppHandle>
+) -> Result<ServerStatus, String> {
+    let id = server_id.unwrap_or_else(|| DEFAULT_SERVER_ID.to_string());
+    let instance = state.instance(&id, port);
+    let target = ServerTarget::loopback(port);
+
+    // First check if server is already running on this port
+    let health_check = check_server_health_impl(&target).await?;
+
+    if health_check {
+        // Server is already running externally - make sure we can actually
+        // speak to it before trusting it.
+        let capabilities =
+            negotiate_capabilities_impl(&target, force_capabilities.unwrap_or(false)).await?;
+
+        let mut status_guard = instance.server_status.lock().unwrap();
+        *status_guard = ServerStatus {
+            id,
+            running: true,
+            port,
+            pid: None,
+            health_url: target.health_url(),
+            host: target.host.clone(),
+            external: true,
+            capabilities: Some(capabilities),
+            process_info: find_process_by_port(port).and_then(describe_process),
+            restart_count: 0,
+            last_exit_code: None,
+        };
+        return Ok(status_guard.clone());
+    }
+
+    // Check if we already have a process, and atomically claim the slot if
+    // not - `server_process` can't be locked across the `.await` points
+    // below (spawning and waiting for readiness), so without `starting` a
+    // second concurrent call here could also see `None` and spawn a
+    // duplicate process that silently overwrites (and orphans) this one
+    // when it installs its own `Child` second.
+    if instance.server_process.lock().unwrap().is_some() {
+        return Err(format!("Server process '{id}' is already managed"));
+    }
+    if instance
+        .starting
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return Err(format!("Server process '{id}' is already being started"));
+    }
+
+    let server_dir = match resolve_server_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            instance.starting.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+
+    let child = spawn_server_child(
+        &server_dir,
+        port,
+        &auth_token,
+        &config_path,
+        &instance.logs,
+        app_handle,
+    );
+
+    match child {
+        Ok(mut child) => {
+            let pid = child.id();
+
+            // Don't trust `running: true` until the port is actually
+            // accepting connections - a freshly spawned Node process can
+            // take a moment (or crash outright) before it's ready.
+            if let Err(ready_err) = wait_for_ready_impl(port, DEFAULT_READY_TIMEOUT, &mut child, &instance.logs).await {
+                let _ = child.kill();
+                instance.starting.store(false, Ordering::SeqCst);
+                let error_msg = format!("Failed to start server: {ready_err}");
+                add_log_entry(&instance.logs, "error", error_msg.clone(), app_handle);
+                return Err(error_msg);
             }
-            
-            *process_guard = Some(child);
 
-            // Update status
-            let mut status_guard = state.server_status.lock().unwrap();
+            let capabilities = negotiate_capabilities_impl(&target, true).await.ok();
+
+            *instance.server_process.lock().unwrap() = Some(child);
+            instance.starting.store(false, Ordering::SeqCst);
+
+            let mut status_guard = instance.server_status.lock().unwrap();
             *status_guard = ServerStatus {
+                id: id.clone(),
                 running: true,
                 port,
                 pid: Some(pid),
-                health_url: format!("http://localhost:{port}/health"),
+                health_url: target.health_url(),
+                host: target.host.clone(),
                 external: false,
+                capabilities,
+                process_info: describe_process(pid),
+                restart_count: 0,
+                last_exit_code: None,
             };
-            
+            let status = status_guard.clone();
+            drop(status_guard);
+
             // Add start log entry
-            add_log_entry(&state.logs, "info", format!("Server started on port {} (PID: {})", port, pid), app_handle);
+            add_log_entry(&instance.logs, "info", format!("Server '{id}' started on port {port} (PID: {pid})"), app_handle);
 
-            Ok(status_guard.clone())
+            // Hand the process off to the supervisor so a crash gets
+            // noticed and (if configured) auto-restarted.
+            if let Some(handle) = app_handle {
+                let config = instance.supervisor_config.lock().unwrap().clone();
+                start_supervisor(handle.clone(), id, Arc::clone(&instance), config, port, auth_token, config_path);
+            }
+
+            Ok(status)
         }
-        Err(e) => {
-            let error_msg = format!("Failed to start server: {e}");
-            add_log_entry(&state.logs, "error", error_msg.clone(), app_handle);
+        Err(error_msg) => {
+            instance.starting.store(false, Ordering::SeqCst);
+            add_log_entry(&instance.logs, "error", error_msg.clone(), app_handle);
             Err(error_msg)
         }
     }
 }
 
+pub fn configure_supervisor_impl(
+    state: &AppState,
+    server_id: Option<ServerId>,
+    max_restarts: u32,
+    base_delay_ms: u64,
+    max_delay_ms: u64,
+    enabled: bool,
+) -> SupervisorConfig {
+    let id = server_id.unwrap_or_else(|| DEFAULT_SERVER_ID.to_string());
+    let instance = state.instance(&id, DEFAULT_INSTANCE_PORT);
+    let mut config_guard = instance.supervisor_config.lock().unwrap();
+    *config_guard = SupervisorConfig {
+        max_restarts,
+        base_delay_ms,
+        max_delay_ms,
+        enabled,
+    };
+    *config_guard
+}
+
+/// Replace whatever supervisor is watching `instance` with a fresh one and
+/// kick off its background task. Called once, right after the initial
+/// successful spawn in `start_server_impl`; restarts after that are done
+/// inside the same `supervise` task.
+fn start_supervisor(
+    app_handle: AppHandle,
+    id: ServerId,
+    instance: Arc<ServerInstance>,
+    config: SupervisorConfig,
+    port: u16,
+    auth_token: Option<String>,
+    config_path: Option<String>,
+) {
+    if !config.enabled {
+        *instance.supervisor.lock().unwrap() = None;
+        return;
+    }
+
+    let handle = SupervisorHandle::default();
+    let stopping = Arc::clone(&handle.stopping);
+    let attempt = Arc::clone(&handle.attempt);
+    *instance.supervisor.lock().unwrap() = Some(handle);
+
+    tauri::async_runtime::spawn(supervise(app_handle, id, instance, config, port, auth_token, config_path, stopping, attempt));
+}
+
+/// Capped exponential backoff for the `attempt`-th restart. `configure_supervisor`
+/// accepts any `u32` for `max_restarts`, so `attempt` isn't bounded to `u64`'s
+/// shift range - `checked_shl` avoids the panic (debug) / shift-mask
+/// (release) that `1u64 << (attempt - 1)` would hit once `attempt` exceeds 64.
+fn backoff_delay_ms(config: &SupervisorConfig, attempt: u32) -> u64 {
+    let backoff_factor = 1u64.checked_shl(attempt - 1).unwrap_or(u64::MAX);
+    config.base_delay_ms.saturating_mul(backoff_factor).min(config.max_delay_ms)
+}
+
+/// Background loop that reaps the child's exit status and, unless the exit
+/// was requested via `stop_server_impl`, respawns it with a capped
+/// exponential backoff until `max_restarts` is exhausted.
+async fn supervise(
+    app_handle: AppHandle,
+    id: ServerId,
+    instance: Arc<ServerInstance>,
+    config: SupervisorConfig,
+    port: u16,
+    auth_token: Option<String>,
+    config_path: Option<String>,
+    stopping: Arc<AtomicBool>,
+    attempt: Arc<AtomicU32>,
+) {
+    let mut healthy_since: Option<std::time::Instant> = None;
+    // Exit code of a crash whose respawn never produced a ready child. While
+    // set, the next iteration retries the restart instead of reading the
+    // empty `server_process` slot as a stop.
+    let mut pending_restart: Option<Option<i32>> = None;
+
+    loop {
+        sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+        if stopping.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let exited_code = if let Some(code) = pending_restart.take() {
+            Some(code)
+        } else {
+            let mut process_guard = instance.server_process.lock().unwrap();
+            match process_guard.as_mut() {
+                Some(child) => match child.try_wait() {
+                    Ok(Some(status)) => {
+                        *process_guard = None;
+                        Some(status.code())
+                    }
+                    Ok(None) => None,
+                    Err(_) => None,
+                },
+                None => return,
+            }
+        };
+
+        let Some(exit_code) = exited_code else {
+            // Still alive - track how long it's been healthy so we can
+            // eventually forgive past restarts.
+            if check_server_health_impl(&ServerTarget::loopback(port)).await.unwrap_or(false) {
+                match healthy_since {
+                    Some(since) if since.elapsed() >= SUPERVISOR_STABLE_WINDOW => {
+                        attempt.store(0, Ordering::SeqCst);
+                    }
+                    Some(_) => {}
+                    None => healthy_since = Some(std::time::Instant::now()),
+                }
+            } else {
+                healthy_since = None;
+            }
+            continue;
+        };
+
+        healthy_since = None;
+
+        if stopping.load(Ordering::SeqCst) {
+            return;
+        }
+
+        add_log_entry(
+            &instance.logs,
+            "error",
+            format!("Server '{id}' on port {port} exited unexpectedly (code: {exit_code:?})"),
+            Some(&app_handle),
+        );
+
+        {
+            let mut status_guard = instance.server_status.lock().unwrap();
+            status_guard.running = false;
+            status_guard.pid = None;
+            status_guard.last_exit_code = exit_code;
+        }
+        let _ = app_handle.emit("server-status-changed", instance.server_status.lock().unwrap().clone());
+
+        // Re-read the live config rather than trusting the value captured
+        // when the supervisor was spawned, so a `configure_supervisor` call
+        // made after start_server takes effect on the very next restart
+        // instead of only on a fresh start_server.
+        let config = instance.supervisor_config.lock().unwrap().clone();
+        if !config.enabled {
+            return;
+        }
+
+        let current_attempt = attempt.fetch_add(1, Ordering::SeqCst) + 1;
+        if current_attempt > config.max_restarts {
+            add_log_entry(
+                &instance.logs,
+                "error",
+                format!("Server '{id}' on port {port} exhausted {} restart attempts; giving up", config.max_restarts),
+                Some(&app_handle),
+            );
+            let _ = app_handle.emit("server-restarts-exhausted", port);
+            return;
+        }
+
+        sleep(Duration::from_millis(backoff_delay_ms(&config, current_attempt))).await;
+
+        let server_dir = match resolve_server_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                add_log_entry(&instance.logs, "error", format!("Supervisor could not locate server directory: {e}"), Some(&app_handle));
+                return;
+            }
+        };
+
+        match spawn_server_child(&server_dir, port, &auth_token, &config_path, &instance.logs, Some(&app_handle)) {
+            Ok(mut child) => {
+                // Don't report `running: true` until the respawned process is
+                // actually accepting connections - the same race start_server_impl
+                // guards against, reusing wait_for_ready_impl here too.
+                if let Err(ready_err) = wait_for_ready_impl(port, DEFAULT_READY_TIMEOUT, &mut child, &instance.logs).await {
+                    let _ = child.kill();
+                    add_log_entry(
+                        &instance.logs,
+                        "error",
+                        format!("Supervisor restarted server '{id}' on port {port} but it never became ready: {ready_err}"),
+                        Some(&app_handle),
+                    );
+                    pending_restart = Some(exit_code);
+                } else {
+                    let pid = child.id();
+                    let capabilities = negotiate_capabilities_impl(&ServerTarget::loopback(port), true).await.ok();
+                    *instance.server_process.lock().unwrap() = Some(child);
+                    {
+                        let mut status_guard = instance.server_status.lock().unwrap();
+                        status_guard.running = true;
+                        status_guard.pid = Some(pid);
+                        status_guard.restart_count += 1;
+                        status_guard.capabilities = capabilities;
+                    }
+                    add_log_entry(
+                        &instance.logs,
+                        "info",
+                        format!("Supervisor restarted server '{id}' on port {port} (attempt {current_attempt}/{}, PID: {pid})", config.max_restarts),
+                        Some(&app_handle),
+                    );
+                    let _ = app_handle.emit("server-restarted", current_attempt);
+                }
+            }
+            Err(e) => {
+                add_log_entry(&instance.logs, "error", format!("Supervisor failed to restart server: {e}"), Some(&app_handle));
+                pending_restart = Some(exit_code);
+            }
+        }
+    }
+}
+
+/// Default grace period `stop_server_impl` waits after a polite terminate
+/// signal before escalating to a forced kill.
+const DEFAULT_GRACE_PERIOD_MS: u64 = 5_000;
+
+/// Send a polite "please exit" request to a process: `SIGTERM` on Unix via
+/// `sysinfo`, or `taskkill` without `/F` on Windows (sysinfo has no
+/// portable way to ask Windows processes to close gracefully).
+fn send_terminate_signal(pid: u32) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        let mut system = System::new();
+        let sys_pid = Pid::from_u32(pid);
+        system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[sys_pid]), true);
+        match system.process(sys_pid) {
+            Some(process) => match process.kill_with(sysinfo::Signal::Term) {
+                Some(true) => Ok(()),
+                _ => Err(format!("Failed to send SIGTERM to process {pid}")),
+            },
+            None => Err(format!("No such process: {pid}")),
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        Command::new("taskkill")
+            .args(["/PID", &pid.to_string()])
+            .output()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to request graceful shutdown of process {pid}: {e}"))
+    }
+}
+
+fn is_process_alive(pid: u32) -> bool {
+    let mut system = System::new();
+    let sys_pid = Pid::from_u32(pid);
+    system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[sys_pid]), true);
+    system.process(sys_pid).is_some()
+}
+
+/// Two-phase shutdown: ask the process to exit, wait up to `grace_period`,
+/// and only escalate to a forced kill if it's still alive afterwards.
+/// Returns `true` if the process exited gracefully, `false` if it had to
+/// be force-killed. `child` is used (when present) to reap a managed
+/// process without polling `sysinfo`; an external process is polled by PID.
+async fn shutdown_with_grace_period(
+    pid: u32,
+    child: &mut Option<Child>,
+    grace_period: Duration,
+) -> Result<bool, String> {
+    send_terminate_signal(pid)?;
+
+    let deadline = std::time::Instant::now() + grace_period;
+    loop {
+        let exited = match child.as_mut() {
+            Some(c) => matches!(c.try_wait(), Ok(Some(_))),
+            None => !is_process_alive(pid),
+        };
+        if exited {
+            return Ok(true);
+        }
+        if std::time::Instant::now() >= deadline {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    // Still alive after the grace period - escalate to a forced kill.
+    match child.as_mut() {
+        Some(c) => c
+            .kill()
+            .map_err(|e| format!("Failed to force-kill process {pid}: {e}"))?,
+        None => kill_process(pid)?,
+    }
+    Ok(false)
+}
+
 pub async fn stop_server_impl(
     state: &AppState,
+    server_id: Option<ServerId>,
     force_external: Option<bool>,
+    grace_period_ms: Option<u64>,
     app_handle: Option<&AppHandle>
-) -> Result<(), String> {
-    let status_guard = state.server_status.lock().unwrap();
+) -> Result<bool, String> {
+    let id = server_id.unwrap_or_else(|| DEFAULT_SERVER_ID.to_string());
+    let Some(instance) = state.find_instance(&id) else {
+        return Err(format!("No server instance with id '{id}'"));
+    };
+
+    let status_guard = instance.server_status.lock().unwrap();
     let is_external = status_guard.external;
     let port = status_guard.port;
+    let pid = status_guard.pid;
+    let host = status_guard.host.clone();
     drop(status_guard); // Release the lock
 
     // If it's an external server and force_external is not true, return error
@@ -332,118 +1233,366 @@ pub async fn stop_server_impl(
         );
     }
 
-    let mut process_guard = state.server_process.lock().unwrap();
+    // Tell the supervisor this exit is intentional so it doesn't try to
+    // restart the server out from under us.
+    if let Some(supervisor) = instance.supervisor.lock().unwrap().as_ref() {
+        supervisor.stopping.store(true, Ordering::SeqCst);
+    }
+
+    let grace_period = Duration::from_millis(grace_period_ms.unwrap_or(DEFAULT_GRACE_PERIOD_MS));
+
+    let mut process_guard = instance.server_process.lock().unwrap();
+
+    // If we have a managed process, shut it down
+    if let Some(child) = process_guard.take() {
+        let Some(managed_pid) = pid.or(Some(child.id())) else {
+            return Err("Managed process has no PID".to_string());
+        };
+        let mut child_slot = Some(child);
+        drop(process_guard);
 
-    // If we have a managed process, kill it
-    if let Some(mut child) = process_guard.take() {
-        match child.kill() {
-            Ok(_) => {
-                let mut status_guard = state.server_status.lock().unwrap();
+        return match shutdown_with_grace_period(managed_pid, &mut child_slot, grace_period).await {
+            Ok(graceful) => {
+                let mut status_guard = instance.server_status.lock().unwrap();
                 status_guard.running = false;
                 status_guard.pid = None;
                 status_guard.external = false;
-                add_log_entry(&state.logs, "info", "Server stopped successfully".to_string(), app_handle);
-                return Ok(());
+                status_guard.process_info = None;
+                let message = if graceful {
+                    "Server stopped gracefully"
+                } else {
+                    "Server did not exit within the grace period; force-killed"
+                };
+                add_log_entry(&instance.logs, "info", message.to_string(), app_handle);
+                Ok(graceful)
             }
-            Err(e) => return Err(format!("Failed to stop managed server: {e}")),
-        }
+            Err(e) => {
+                let error_msg = format!("Failed to stop managed server: {e}");
+                add_log_entry(&instance.logs, "error", error_msg.clone(), app_handle);
+                Err(error_msg)
+            }
+        };
     }
+    drop(process_guard);
 
-    // If it's an external server and force_external is true, find and kill the process
+    // If it's an external server and force_external is true, shut it down
     if is_external && force_external.unwrap_or(false) {
+        // `find_process_by_port`/`kill_process` only inspect this machine -
+        // for a remote target they'd find (and kill) an unrelated local
+        // process that happens to share the port number instead.
+        if !ServerTarget::is_loopback_host(&host) {
+            return Err(format!(
+                "Cannot force-stop '{host}:{port}': it's a remote server, and force-stop only works on processes running on this machine"
+            ));
+        }
         if let Some(pid) = find_process_by_port(port) {
-            #[cfg(unix)]
-            {
-                let output = Command::new("kill")
-                    .arg("-9")
-                    .arg(pid.to_string())
-                    .output()
-                    .map_err(|e| format!("Failed to execute kill command: {e}"))?;
-
-                if output.status.success() {
-                    let mut status_guard = state.server_status.lock().unwrap();
+            let mut no_child = None;
+            match shutdown_with_grace_period(pid, &mut no_child, grace_period).await {
+                Ok(graceful) => {
+                    let mut status_guard = instance.server_status.lock().unwrap();
                     status_guard.running = false;
                     status_guard.pid = None;
                     status_guard.external = false;
-                    add_log_entry(&state.logs, "info", format!("External server on port {} stopped successfully", port), app_handle);
-                    return Ok(());
-                } else {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    let error_msg = format!("Failed to kill process {pid}: {stderr}");
-                    add_log_entry(&state.logs, "error", error_msg.clone(), app_handle);
-                    return Err(error_msg);
+                    status_guard.process_info = None;
+                    let message = if graceful {
+                        format!("External server on port {port} stopped gracefully")
+                    } else {
+                        format!("External server on port {port} did not exit within the grace period; force-killed")
+                    };
+                    add_log_entry(&instance.logs, "info", message, app_handle);
+                    Ok(graceful)
                 }
-            }
-
-            #[cfg(windows)]
-            {
-                let output = Command::new("taskkill")
-                    .args(["/F", "/PID", &pid.to_string()])
-                    .output()
-                    .map_err(|e| format!("Failed to execute taskkill command: {e}"))?;
-
-                if output.status.success() {
-                    let mut status_guard = state.server_status.lock().unwrap();
-                    status_guard.running = false;
-                    status_guard.pid = None;
-                    status_guard.external = false;
-                    add_log_entry(&state.logs, "info", format!("External server on port {} stopped successfully", port), app_handle);
-                    return Ok(());
-                } else {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    let error_msg = format!("Failed to kill process {pid}: {stderr}");
-                    add_log_entry(&state.logs, "error", error_msg.clone(), app_handle);
-                    return Err(error_msg);
+                Err(error_msg) => {
+                    add_log_entry(&instance.logs, "error", error_msg.clone(), app_handle);
+                    Err(error_msg)
                 }
             }
         } else {
-            return Err(format!("Could not find process listening on port {port}"));
+            Err(format!("Could not find process listening on port {port}"))
         }
+    } else {
+        Err("Server is not running".to_string())
     }
+}
 
-    Err("Server is not running".to_string())
+/// Default timeout for a single `/health` request. A bare `reqwest::get`
+/// has no timeout at all, so a hung socket could block `detect_running_server`
+/// or `start_server` indefinitely without this.
+const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+/// Default deadline for `wait_for_ready_impl` to see the server come up
+/// after spawning it.
+const DEFAULT_READY_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often `wait_for_ready_impl` polls while waiting for readiness.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub async fn check_server_health_impl(target: &ServerTarget) -> Result<bool, String> {
+    check_server_health_with_timeout_impl(target, DEFAULT_HEALTH_CHECK_TIMEOUT).await
 }
 
-pub async fn check_server_health_impl(port: u16) -> Result<bool, String> {
-    let url = format!("http://localhost:{port}/health");
+pub async fn check_server_health_with_timeout_impl(
+    target: &ServerTarget,
+    timeout: Duration,
+) -> Result<bool, String> {
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
 
-    match reqwest::get(&url).await {
+    match client.get(target.health_url()).send().await {
         Ok(response) => Ok(response.status().is_success()),
         Err(_) => Ok(false),
     }
 }
 
-pub fn get_server_status_impl(state: &AppState) -> ServerStatus {
-    state.server_status.lock().unwrap().clone()
+/// Number of trailing stderr lines to surface when a server dies during
+/// its readiness window, so the caller's error actually says why.
+const READY_STDERR_TAIL_LINES: usize = 20;
+
+/// Poll `/health` on a fixed interval until the server responds or
+/// `timeout` elapses, so callers get a distinct "started but failed to
+/// become healthy" error instead of a false-positive `running: true`.
+/// Concurrently reaps `child` each iteration so a crash during startup is
+/// reported immediately (with recent stderr) instead of waiting out the
+/// full timeout.
+pub async fn wait_for_ready_impl(
+    port: u16,
+    timeout: Duration,
+    child: &mut Child,
+    logs: &Arc<Mutex<LogsState>>,
+) -> Result<(), String> {
+    let deadline = std::time::Instant::now() + timeout;
+
+    loop {
+        if let Ok(Some(status)) = child.try_wait() {
+            let tail = recent_stderr_lines(logs, READY_STDERR_TAIL_LINES);
+            let context = if tail.is_empty() {
+                String::new()
+            } else {
+                format!("\nLast output:\n{}", tail.join("\n"))
+            };
+            return Err(format!(
+                "Server on port {port} exited during startup (code: {:?}){context}",
+                status.code()
+            ));
+        }
+
+        if check_server_health_with_timeout_impl(&ServerTarget::loopback(port), DEFAULT_HEALTH_CHECK_TIMEOUT)
+            .await
+            .unwrap_or(false)
+        {
+            return Ok(());
+        }
+
+        if std::time::Instant::now() >= deadline {
+            return Err(format!(
+                "Server on port {port} started but failed to become healthy within {}ms",
+                timeout.as_millis()
+            ));
+        }
+
+        sleep(READY_POLL_INTERVAL).await;
+    }
+}
+
+fn recent_stderr_lines(logs: &Arc<Mutex<LogsState>>, n: usize) -> Vec<String> {
+    let logs_guard = logs.lock().unwrap();
+    let mut tail: Vec<String> = logs_guard
+        .entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.stream == "stderr")
+        .take(n)
+        .map(|entry| entry.message.clone())
+        .collect();
+    tail.reverse();
+    tail
+}
+
+pub fn get_server_status_impl(state: &AppState, server_id: Option<&str>) -> ServerStatus {
+    let id = server_id.unwrap_or(DEFAULT_SERVER_ID);
+    match state.find_instance(id) {
+        Some(instance) => instance.server_status.lock().unwrap().clone(),
+        None => ServerStatus::not_running(id.to_string()),
+    }
+}
+
+/// Statuses of every instance currently in the registry, for a UI that
+/// wants to show all servers the host app knows about at once.
+pub fn list_servers_impl(state: &AppState) -> Vec<ServerStatus> {
+    state
+        .instances
+        .lock()
+        .unwrap()
+        .values()
+        .map(|instance| instance.server_status.lock().unwrap().clone())
+        .collect()
 }
 
 pub async fn detect_running_server_impl(
     state: &AppState,
-    port: u16,
+    server_id: Option<ServerId>,
+    target: ServerTarget,
+    force_capabilities: Option<bool>,
 ) -> Result<ServerStatus, String> {
-    let is_running = check_server_health_impl(port).await?;
-
-    let mut status_guard = state.server_status.lock().unwrap();
+    let id = server_id.unwrap_or_else(|| DEFAULT_SERVER_ID.to_string());
+    let port = target.port;
+    let instance = state.instance(&id, port);
+    let is_running = check_server_health_impl(&target).await?;
 
     if is_running {
-        // Check if we have a managed process
-        let process_guard = state.server_process.lock().unwrap();
-        let is_external = process_guard.is_none();
+        let capabilities =
+            negotiate_capabilities_impl(&target, force_capabilities.unwrap_or(false)).await?;
+
+        // A non-loopback target can't be a process we spawned, and isn't
+        // something `find_process_by_port`/`describe_process` (which only
+        // see processes on this machine) can say anything about.
+        let (is_external, process_info) = if target.is_loopback() {
+            let process_guard = instance.server_process.lock().unwrap();
+            let is_external = process_guard.is_none();
+            drop(process_guard);
+            (is_external, find_process_by_port(port).and_then(describe_process))
+        } else {
+            (true, None)
+        };
 
+        let mut status_guard = instance.server_status.lock().unwrap();
+        let restart_count = status_guard.restart_count;
+        let last_exit_code = status_guard.last_exit_code;
         *status_guard = ServerStatus {
+            id,
             running: true,
             port,
             pid: None,
-            health_url: format!("http://localhost:{port}/health"),
+            health_url: target.health_url(),
+            host: target.host.clone(),
             external: is_external,
+            capabilities: Some(capabilities),
+            process_info,
+            restart_count,
+            last_exit_code,
         };
+        Ok(status_guard.clone())
     } else {
+        let mut status_guard = instance.server_status.lock().unwrap();
         status_guard.running = false;
         status_guard.external = false;
         status_guard.pid = None;
+        status_guard.capabilities = None;
+        status_guard.process_info = None;
+        Ok(status_guard.clone())
+    }
+}
+
+/// GET `/capabilities` (falling back to a JSON `/health` body) and make
+/// sure the server advertises everything the host app needs before it is
+/// treated as ready to drive. Mirroring `stop_server_impl`'s
+/// `force_external`, passing `force: true` accepts a server whose advertised
+/// features or protocol version fall short instead of refusing it - for an
+/// operator who knows what they're doing and wants to attach anyway. Failing
+/// to fetch the capabilities at all is still an error, forced or not.
+pub async fn negotiate_capabilities_impl(
+    target: &ServerTarget,
+    force: bool,
+) -> Result<ServerCapabilities, String> {
+    let capabilities = fetch_capabilities(target).await?;
+
+    match check_capabilities(&capabilities) {
+        Ok(()) => Ok(capabilities),
+        Err(_) if force => Ok(capabilities),
+        Err(e) => Err(e),
+    }
+}
+
+fn check_capabilities(capabilities: &ServerCapabilities) -> Result<(), String> {
+    let missing: Vec<&str> = REQUIRED_CAPABILITIES
+        .iter()
+        .copied()
+        .filter(|required| !capabilities.features.iter().any(|f| f == required))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "Server is missing required capabilities: {}",
+            missing.join(", ")
+        ));
+    }
+
+    let server_version = Version::parse(&capabilities.protocol_version).map_err(|e| {
+        format!(
+            "Server reported an unparsable protocol version '{}': {e}",
+            capabilities.protocol_version
+        )
+    })?;
+    let min_version =
+        Version::parse(MIN_SERVER_VERSION).expect("MIN_SERVER_VERSION is valid semver");
+    if server_version < min_version {
+        return Err(format!(
+            "Server protocol version {} is older than the minimum supported version {MIN_SERVER_VERSION}",
+            capabilities.protocol_version
+        ));
+    }
+
+    Ok(())
+}
+
+async fn fetch_capabilities(target: &ServerTarget) -> Result<ServerCapabilities, String> {
+    // A bare `reqwest::get` has no timeout - since this runs on every
+    // `start_server_impl`/`detect_running_server_impl` right after the
+    // health check passes, a server that accepts the connection but hangs
+    // here would block them indefinitely, the same unbounded-hang
+    // `check_server_health_impl` is timed out against.
+    let client = reqwest::Client::builder()
+        .timeout(DEFAULT_HEALTH_CHECK_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
+
+    let capabilities_url = format!("{}/capabilities", target.base_url());
+    if let Ok(response) = client.get(&capabilities_url).send().await {
+        if response.status().is_success() {
+            if let Ok(capabilities) = response.json::<ServerCapabilities>().await {
+                return Ok(capabilities);
+            }
+        }
     }
 
-    Ok(status_guard.clone())
+    // Older servers don't expose a dedicated endpoint - fall back to
+    // version/feature fields embedded in the JSON `/health` body.
+    let response = client
+        .get(target.health_url())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach server health endpoint: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Server health endpoint returned {}",
+            response.status()
+        ));
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Server health response was not valid JSON: {e}"))?;
+
+    let protocol_version = body
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("0.0.0")
+        .to_string();
+    let features = body
+        .get("features")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ServerCapabilities {
+        protocol_version,
+        features,
+    })
 }
 
 #[cfg(test)]
@@ -463,7 +1612,7 @@ mod tests {
     #[test]
     fn test_app_state_creation() {
         let state = AppState::new();
-        let status = state.server_status.lock().unwrap();
+        let status = get_server_status_impl(&state, None);
         assert_eq!(status.running, false);
         assert_eq!(status.port, 3001);
         assert_eq!(status.pid, None);
@@ -473,11 +1622,17 @@ mod tests {
     #[test]
     fn test_server_status_serialization() {
         let status = ServerStatus {
+            id: DEFAULT_SERVER_ID.to_string(),
             running: true,
             port: 3001,
             pid: Some(12345),
             health_url: "http://localhost:3001/health".to_string(),
+            host: "localhost".to_string(),
             external: false,
+            capabilities: None,
+            process_info: None,
+            restart_count: 0,
+            last_exit_code: None,
         };
 
         let json = serde_json::to_string(&status).unwrap();
@@ -496,25 +1651,54 @@ mod tests {
     #[tokio::test]
     async fn test_check_server_health_impl() {
         // Test with a port that's unlikely to be running
-        let result = check_server_health_impl(65432).await;
+        let result = check_server_health_impl(&ServerTarget::loopback(65432)).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), false);
     }
 
+    #[test]
+    fn test_server_target_urls() {
+        let local = ServerTarget::loopback(3001);
+        assert!(local.is_loopback());
+        assert_eq!(local.health_url(), "http://localhost:3001/health");
+
+        let remote = ServerTarget::new("192.168.1.50", 3001, Scheme::Https);
+        assert!(!remote.is_loopback());
+        assert_eq!(remote.base_url(), "https://192.168.1.50:3001");
+        assert_eq!(remote.health_url(), "https://192.168.1.50:3001/health");
+    }
+
     #[test]
     fn test_get_server_status_impl() {
         let state = AppState::new();
-        let status = get_server_status_impl(&state);
+        let status = get_server_status_impl(&state, None);
         assert_eq!(status.running, false);
         assert_eq!(status.port, 3001);
         assert_eq!(status.pid, None);
         assert_eq!(status.external, false);
     }
 
+    #[test]
+    fn test_get_server_status_impl_unknown_instance() {
+        let state = AppState::new();
+        let status = get_server_status_impl(&state, Some("does-not-exist"));
+        assert_eq!(status.running, false);
+        assert_eq!(status.id, "does-not-exist");
+    }
+
+    #[test]
+    fn test_list_servers_impl() {
+        let state = AppState::new();
+        let servers = list_servers_impl(&state);
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].id, DEFAULT_SERVER_ID);
+    }
+
     #[tokio::test]
     async fn test_detect_running_server_not_running() {
         let state = AppState::new();
-        let result = detect_running_server_impl(&state, 65432).await;
+        let result =
+            detect_running_server_impl(&state, None, ServerTarget::loopback(65432), None).await;
         assert!(result.is_ok());
         let status = result.unwrap();
         assert_eq!(status.running, false);
@@ -532,25 +1716,151 @@ mod tests {
     #[tokio::test]
     async fn test_stop_server_not_started() {
         let state = AppState::new();
-        let result = stop_server_impl(&state, None).await;
+        let result = stop_server_impl(&state, None, None, None, None).await;
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), "Server is not running");
     }
 
+    #[tokio::test]
+    async fn test_stop_unknown_instance() {
+        let state = AppState::new();
+        let result = stop_server_impl(&state, Some("does-not-exist".to_string()), None, None, None).await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("No server instance"));
+    }
+
     #[tokio::test]
     async fn test_stop_external_server_without_force() {
         let state = AppState::new();
         {
-            let mut status = state.server_status.lock().unwrap();
+            let instances = state.instances.lock().unwrap();
+            let instance = instances.get(DEFAULT_SERVER_ID).unwrap();
+            let mut status = instance.server_status.lock().unwrap();
             status.external = true;
             status.running = true;
         }
 
-        let result = stop_server_impl(&state, Some(false)).await;
+        let result = stop_server_impl(&state, None, Some(false), None, None).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("not started by this app"));
     }
 
+    #[tokio::test]
+    async fn test_stop_external_remote_server_force_refused() {
+        let state = AppState::new();
+        {
+            let instances = state.instances.lock().unwrap();
+            let instance = instances.get(DEFAULT_SERVER_ID).unwrap();
+            let mut status = instance.server_status.lock().unwrap();
+            status.external = true;
+            status.running = true;
+            status.host = "192.168.1.50".to_string();
+        }
+
+        let result = stop_server_impl(&state, None, Some(true), None, None).await;
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.contains("remote server"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn test_parse_structured_log_line_json() {
+        let line = r#"{"level":"warn","msg":"disk space low","module":"watcher","extra":1}"#;
+        let parsed = parse_structured_log_line(line).unwrap();
+        assert_eq!(parsed.level, "warn");
+        assert_eq!(parsed.message, "disk space low");
+        assert_eq!(parsed.component.as_deref(), Some("watcher"));
+        assert_eq!(parsed.fields.get("extra").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_parse_structured_log_line_non_json() {
+        assert!(parse_structured_log_line("plain text log line").is_none());
+    }
+
+    #[test]
+    fn test_get_logs_filtered_impl() {
+        let state = AppState::new();
+        {
+            let instances = state.instances.lock().unwrap();
+            let instance = instances.get(DEFAULT_SERVER_ID).unwrap();
+            let mut logs = instance.logs.lock().unwrap();
+            logs.entries.push(LogEntry {
+                timestamp: "t1".to_string(),
+                level: "debug".to_string(),
+                message: "starting up".to_string(),
+                stream: "stdout".to_string(),
+                component: Some("boot".to_string()),
+                fields: HashMap::new(),
+            });
+            logs.entries.push(LogEntry {
+                timestamp: "t2".to_string(),
+                level: "error".to_string(),
+                message: "connection refused".to_string(),
+                stream: "stderr".to_string(),
+                component: Some("network".to_string()),
+                fields: HashMap::new(),
+            });
+        }
+
+        let errors_only = get_logs_filtered_impl(&state, None, Some("error".to_string()), None, None, None);
+        assert_eq!(errors_only.len(), 1);
+        assert_eq!(errors_only[0].message, "connection refused");
+
+        let by_component = get_logs_filtered_impl(&state, None, None, Some("boot".to_string()), None, None);
+        assert_eq!(by_component.len(), 1);
+        assert_eq!(by_component[0].component.as_deref(), Some("boot"));
+
+        let by_substring = get_logs_filtered_impl(&state, None, None, None, Some("refused".to_string()), None);
+        assert_eq!(by_substring.len(), 1);
+    }
+
+    #[test]
+    fn test_backoff_delay_ms_caps_at_large_attempt() {
+        let config = SupervisorConfig {
+            max_restarts: 100,
+            base_delay_ms: 500,
+            max_delay_ms: 30_000,
+            enabled: true,
+        };
+        // Attempts large enough to overflow a plain `1u64 << (attempt - 1)`
+        // must still saturate at `max_delay_ms` instead of panicking/wrapping.
+        assert_eq!(backoff_delay_ms(&config, 65), config.max_delay_ms);
+        assert_eq!(backoff_delay_ms(&config, 1000), config.max_delay_ms);
+        assert_eq!(backoff_delay_ms(&config, 1), config.base_delay_ms);
+    }
+
+    #[test]
+    fn test_log_level_rank_aliases() {
+        // pino/bunyan-style aliases should rank alongside their canonical
+        // `LOG_LEVELS` counterpart rather than falling back to "info".
+        assert_eq!(log_level_rank("warn"), log_level_rank("warning"));
+        assert_eq!(log_level_rank("fatal"), log_level_rank("error"));
+        assert_eq!(log_level_rank("crit"), log_level_rank("error"));
+        assert_eq!(log_level_rank("trace"), log_level_rank("debug"));
+    }
+
+    #[test]
+    fn test_get_logs_filtered_impl_min_level_alias() {
+        let state = AppState::new();
+        {
+            let instances = state.instances.lock().unwrap();
+            let instance = instances.get(DEFAULT_SERVER_ID).unwrap();
+            let mut logs = instance.logs.lock().unwrap();
+            logs.entries.push(LogEntry {
+                timestamp: "t1".to_string(),
+                level: "warn".to_string(),
+                message: "disk space low".to_string(),
+                stream: "stdout".to_string(),
+                component: None,
+                fields: HashMap::new(),
+            });
+        }
+
+        let matches = get_logs_filtered_impl(&state, None, Some("warning".to_string()), None, None, None);
+        assert_eq!(matches.len(), 1, "a \"warn\" entry should satisfy a \"warning\" min_level filter");
+    }
+
     #[test]
     fn test_network_info_serialization() {
         let info = NetworkInfo {
@@ -3,11 +3,31 @@
 use tauri::State;
 
 use aicli_companion_hostapp::{
-    check_server_health_impl, detect_running_server_impl, get_local_ip as get_local_ip_impl,
-    get_server_status_impl, start_server_impl, stop_server_impl, AppState, ServerStatus,
+    check_server_health_impl, configure_supervisor_impl, detect_running_server_impl,
+    export_logs_impl, get_local_ip as get_local_ip_impl, get_logs_filtered_impl,
+    get_server_status_impl, list_servers_impl, negotiate_capabilities_impl,
+    shareable_network_info_impl, start_server_impl, stop_server_impl, AppState, LogExportFormat,
+    NetworkInfo, Scheme, ServerCapabilities, ServerStatus, ServerTarget, SupervisorConfig,
     get_logs_impl, clear_logs_impl, LogEntry,
 };
 
+/// Build the target a command should reach: loopback unless the caller
+/// supplies a LAN/remote `host`, so existing callers that only pass `port`
+/// keep talking to the locally managed server.
+fn resolve_target(host: Option<String>, port: u16, use_https: Option<bool>) -> ServerTarget {
+    match host {
+        Some(host) => {
+            let scheme = if use_https.unwrap_or(false) {
+                Scheme::Https
+            } else {
+                Scheme::Http
+            };
+            ServerTarget::new(host, port, scheme)
+        }
+        None => ServerTarget::loopback(port),
+    }
+}
+
 #[tauri::command]
 fn get_local_ip() -> Result<String, String> {
     get_local_ip_impl()
@@ -15,50 +35,127 @@ fn get_local_ip() -> Result<String, String> {
 
 #[tauri::command]
 async fn start_server(
-    state: State<'_, AppState>, 
+    state: State<'_, AppState>,
+    server_id: Option<String>,
     port: u16,
     auth_token: Option<String>,
     config_path: Option<String>,
+    force_capabilities: Option<bool>,
     app_handle: tauri::AppHandle
 ) -> Result<ServerStatus, String> {
-    start_server_impl(&state, port, auth_token, config_path, Some(&app_handle)).await
+    start_server_impl(
+        &state,
+        server_id,
+        port,
+        auth_token,
+        config_path,
+        force_capabilities,
+        Some(&app_handle),
+    )
+    .await
 }
 
 #[tauri::command]
 async fn stop_server(
     state: State<'_, AppState>,
+    server_id: Option<String>,
     force_external: Option<bool>,
+    grace_period_ms: Option<u64>,
     app_handle: tauri::AppHandle
-) -> Result<(), String> {
-    stop_server_impl(&state, force_external, Some(&app_handle)).await
+) -> Result<bool, String> {
+    stop_server_impl(&state, server_id, force_external, grace_period_ms, Some(&app_handle)).await
+}
+
+#[tauri::command]
+async fn check_server_health(
+    port: u16,
+    host: Option<String>,
+    use_https: Option<bool>,
+) -> Result<bool, String> {
+    check_server_health_impl(&resolve_target(host, port, use_https)).await
 }
 
 #[tauri::command]
-async fn check_server_health(port: u16) -> Result<bool, String> {
-    check_server_health_impl(port).await
+fn get_server_status(state: State<'_, AppState>, server_id: Option<String>) -> ServerStatus {
+    get_server_status_impl(&state, server_id.as_deref())
 }
 
 #[tauri::command]
-fn get_server_status(state: State<'_, AppState>) -> ServerStatus {
-    get_server_status_impl(&state)
+fn list_servers(state: State<'_, AppState>) -> Vec<ServerStatus> {
+    list_servers_impl(&state)
 }
 
 #[tauri::command]
 async fn detect_running_server(
     state: State<'_, AppState>,
+    server_id: Option<String>,
     port: u16,
+    host: Option<String>,
+    use_https: Option<bool>,
+    force_capabilities: Option<bool>,
 ) -> Result<ServerStatus, String> {
-    detect_running_server_impl(&state, port).await
+    let target = resolve_target(host, port, use_https);
+    detect_running_server_impl(&state, server_id, target, force_capabilities).await
+}
+
+#[tauri::command]
+fn get_logs(state: State<'_, AppState>, server_id: Option<String>) -> Vec<LogEntry> {
+    get_logs_impl(&state, server_id.as_deref())
+}
+
+#[tauri::command]
+fn clear_logs(state: State<'_, AppState>, server_id: Option<String>) {
+    clear_logs_impl(&state, server_id.as_deref())
 }
 
 #[tauri::command]
-fn get_logs(state: State<'_, AppState>) -> Vec<LogEntry> {
-    get_logs_impl(&state)
+fn get_logs_filtered(
+    state: State<'_, AppState>,
+    server_id: Option<String>,
+    min_level: Option<String>,
+    component: Option<String>,
+    substring: Option<String>,
+    limit: Option<usize>,
+) -> Vec<LogEntry> {
+    get_logs_filtered_impl(&state, server_id.as_deref(), min_level, component, substring, limit)
+}
+
+#[tauri::command]
+fn export_logs(
+    state: State<'_, AppState>,
+    server_id: Option<String>,
+    path: String,
+    format: LogExportFormat,
+) -> Result<(), String> {
+    export_logs_impl(&state, server_id.as_deref(), &path, format)
+}
+
+#[tauri::command]
+async fn negotiate_capabilities(
+    port: u16,
+    host: Option<String>,
+    use_https: Option<bool>,
+    force: Option<bool>,
+) -> Result<ServerCapabilities, String> {
+    let target = resolve_target(host, port, use_https);
+    negotiate_capabilities_impl(&target, force.unwrap_or(false)).await
 }
 
 #[tauri::command]
-fn clear_logs(state: State<'_, AppState>) {
-    clear_logs_impl(&state)
+fn get_network_info(port: u16) -> Result<NetworkInfo, String> {
+    shareable_network_info_impl(port)
+}
+
+#[tauri::command]
+fn configure_supervisor(
+    state: State<'_, AppState>,
+    server_id: Option<String>,
+    max_restarts: u32,
+    base_delay_ms: u64,
+    max_delay_ms: u64,
+    enabled: bool,
+) -> SupervisorConfig {
+    configure_supervisor_impl(&state, server_id, max_restarts, base_delay_ms, max_delay_ms, enabled)
 }
 
 fn main() {
@@ -72,9 +169,15 @@ fn main() {
             stop_server,
             check_server_health,
             get_server_status,
+            list_servers,
             detect_running_server,
             get_logs,
-            clear_logs
+            clear_logs,
+            get_logs_filtered,
+            export_logs,
+            configure_supervisor,
+            negotiate_capabilities,
+            get_network_info
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");